@@ -0,0 +1,32 @@
+use crate::models::db::{ClaimsUser, TokenClaims};
+use actix_web::HttpRequest;
+use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+
+fn jwt_secret() -> String {
+    std::env::var("JWT_SECRET").expect("JWT_SECRET must be set")
+}
+
+fn bearer_token(req: &HttpRequest) -> Result<&str, String> {
+    let header = req
+        .headers()
+        .get("Authorization")
+        .ok_or_else(|| "missing Authorization header".to_string())?
+        .to_str()
+        .map_err(|e| e.to_string())?;
+
+    header
+        .strip_prefix("Bearer ")
+        .ok_or_else(|| "Authorization header is not a Bearer token".to_string())
+}
+
+pub fn attempt_req_token_auth(req: &HttpRequest) -> Result<ClaimsUser, String> {
+    let token = bearer_token(req)?;
+
+    decode::<TokenClaims>(
+        token,
+        &DecodingKey::from_secret(jwt_secret().as_bytes()),
+        &Validation::new(Algorithm::HS256),
+    )
+    .map(|data| data.claims.user)
+    .map_err(|e| e.to_string())
+}
@@ -0,0 +1,146 @@
+use crate::models::net::ArticleProgress;
+use serde_json::Value;
+
+/// Lower bound of the comprehensible-input "i+1" band: below this, an article
+/// is mostly unknown vocabulary rather than one new word among known ones.
+pub const IDEAL_COMPREHENSIBILITY_MIN: f64 = 0.90;
+/// Upper bound of the "i+1" band: above this, there's nothing left to learn.
+pub const IDEAL_COMPREHENSIBILITY_MAX: f64 = 0.98;
+
+/// Splits an article's `unique_words` into known-or-learning vs. unknown,
+/// using the per-word `status` the learner has set for `lang`. Plain
+/// `UpdateWordStatusRequest`-style string entries predating SM-2 scheduling
+/// are read as the status itself; words with no entry count as unknown.
+pub fn known_unknown_counts(unique_words: &Value, word_status_data: &Value) -> ArticleProgress {
+    let words = match unique_words.as_object() {
+        Some(words) => words,
+        None => {
+            return ArticleProgress {
+                known_words: 0,
+                unknown_words: 0,
+            }
+        }
+    };
+
+    let mut known_words = 0i64;
+    let mut unknown_words = 0i64;
+
+    for word in words.keys() {
+        let status = word_status_data
+            .get(word)
+            .and_then(|entry| entry.get("status").and_then(Value::as_str).or(entry.as_str()));
+
+        match status {
+            Some("known") | Some("learning") => known_words += 1,
+            _ => unknown_words += 1,
+        }
+    }
+
+    ArticleProgress {
+        known_words,
+        unknown_words,
+    }
+}
+
+pub fn comprehensibility(progress: &ArticleProgress) -> f64 {
+    let total = progress.known_words + progress.unknown_words;
+    if total == 0 {
+        return 0.0;
+    }
+
+    progress.known_words as f64 / total as f64
+}
+
+// above this, there isn't a new word left to meet, so overshoot is penalized
+// much more steeply than falling short of the band
+const OVERSHOOT_PENALTY: f64 = 10.0;
+
+/// Sort key for "i+1" ranking: ~0 for articles inside the ~90-98% known band,
+/// growing for anything further from it. An article above
+/// `IDEAL_COMPREHENSIBILITY_MAX` (nothing left to learn) ranks behind one
+/// that's merely a little below the band, rather than tying with it — a flat
+/// distance-from-midpoint would rank a 100%-known article the same as one at
+/// 88%, surfacing "nothing new" text ahead of genuine i+1 material.
+pub fn comprehensibility_rank(progress: &ArticleProgress) -> f64 {
+    let score = comprehensibility(progress);
+    let midpoint = (IDEAL_COMPREHENSIBILITY_MIN + IDEAL_COMPREHENSIBILITY_MAX) / 2.0;
+
+    if score > IDEAL_COMPREHENSIBILITY_MAX {
+        let overshoot = score - IDEAL_COMPREHENSIBILITY_MAX;
+        (IDEAL_COMPREHENSIBILITY_MAX - midpoint) + overshoot * OVERSHOOT_PENALTY
+    } else {
+        (score - midpoint).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn progress(known: i64, unknown: i64) -> ArticleProgress {
+        ArticleProgress {
+            known_words: known,
+            unknown_words: unknown,
+        }
+    }
+
+    #[test]
+    fn known_unknown_counts_reads_srs_object_entries() {
+        let unique_words = json!({"hund": true, "katze": true, "maus": true});
+        let word_status_data = json!({
+            "hund": {"status": "known", "repetitions": 3, "ease_factor": 2.5, "interval_days": 6, "due": 0},
+            "katze": {"status": "learning", "repetitions": 0, "ease_factor": 2.5, "interval_days": 1, "due": 0},
+        });
+
+        let result = known_unknown_counts(&unique_words, &word_status_data);
+
+        assert_eq!(result.known_words, 2);
+        assert_eq!(result.unknown_words, 1);
+    }
+
+    #[test]
+    fn known_unknown_counts_falls_back_to_legacy_plain_string_status() {
+        let unique_words = json!({"hund": true, "katze": true});
+        let word_status_data = json!({"hund": "known", "katze": "unknown"});
+
+        let result = known_unknown_counts(&unique_words, &word_status_data);
+
+        assert_eq!(result.known_words, 1);
+        assert_eq!(result.unknown_words, 1);
+    }
+
+    #[test]
+    fn known_unknown_counts_treats_missing_entries_as_unknown() {
+        let unique_words = json!({"hund": true});
+        let word_status_data = json!({});
+
+        let result = known_unknown_counts(&unique_words, &word_status_data);
+
+        assert_eq!(result.known_words, 0);
+        assert_eq!(result.unknown_words, 1);
+    }
+
+    #[test]
+    fn comprehensibility_is_zero_for_empty_unique_words() {
+        assert_eq!(comprehensibility(&progress(0, 0)), 0.0);
+    }
+
+    #[test]
+    fn comprehensibility_rank_prefers_the_band_over_a_fully_known_article() {
+        // 100% known has nothing left to teach; 88% known is a near miss.
+        // The near miss should rank ahead of (i.e. below) the fully known one.
+        let fully_known = comprehensibility_rank(&progress(100, 0));
+        let near_miss = comprehensibility_rank(&progress(88, 12));
+
+        assert!(near_miss < fully_known);
+    }
+
+    #[test]
+    fn comprehensibility_rank_is_minimal_inside_the_band() {
+        let in_band = comprehensibility_rank(&progress(94, 6));
+        let below_band = comprehensibility_rank(&progress(80, 20));
+
+        assert!(in_band < below_band);
+    }
+}
@@ -1,29 +1,158 @@
 use jieba_rs::Jieba;
 use lazy_static::lazy_static;
+use pinyin::ToPinyin;
 use serde_json::json;
+use std::collections::HashMap;
+use std::sync::OnceLock;
 use unicode_segmentation::UnicodeSegmentation;
+use vibrato::{Dictionary, Tokenizer as VibratoTokenizer};
 
-pub fn get_words<'a>(text: &'a str, lang: &str) -> Vec<&'a str> {
-    match lang {
-        "en" => get_words_english(text),
-        "zh" => get_words_chinese(text),
-        _ => panic!("Got unsupported language for get_words: {}", text),
+/// A segmenter for one study language, plus an optional reading/transliteration
+/// hook (pinyin for `zh`, furigana/romaji for `ja`) used to annotate words the
+/// pipeline can't expect a learner to sound out from the script alone.
+///
+/// Returns owned `String`s rather than `&str` slices of the input: dictionary
+/// segmenters like vibrato tokenize against their own internal sentence
+/// buffer, not `text`, so a borrowed return type isn't satisfiable for every
+/// implementation the registry needs to support.
+pub trait Tokenizer: Sync + Send {
+    fn tokenize(&self, text: &str) -> Vec<String>;
+
+    /// A reading/transliteration for `word`, if this language has one.
+    fn reading(&self, _word: &str) -> Option<String> {
+        None
+    }
+}
+
+struct EnglishTokenizer;
+
+impl Tokenizer for EnglishTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        text.split_word_bounds().map(String::from).collect()
     }
 }
 
-fn get_words_english<'a>(text: &'a str) -> Vec<&'a str> {
-    text.split_word_bounds().collect::<Vec<&str>>()
+struct ChineseTokenizer;
+
+impl Tokenizer for ChineseTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        JIEBA.cut(text, false).into_iter().map(String::from).collect()
+    }
+
+    fn reading(&self, word: &str) -> Option<String> {
+        let pinyin = word
+            .chars()
+            .filter_map(|c| c.to_pinyin())
+            .map(|p| p.with_tone())
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        if pinyin.is_empty() {
+            None
+        } else {
+            Some(pinyin)
+        }
+    }
+}
+
+struct JapaneseTokenizer {
+    dictionary: Dictionary,
+}
+
+impl JapaneseTokenizer {
+    // `split_word_bounds` has no notion of Japanese word boundaries and jieba
+    // only segments Chinese, so `ja` needs its own dictionary-based segmenter.
+    // Fallible rather than `expect`-ing: this is built lazily on first `ja`
+    // use (see `japanese_tokenizer` below) so a missing/corrupt dictionary
+    // only breaks `ja`, not every other language sharing the registry.
+    fn try_new() -> Result<JapaneseTokenizer, String> {
+        let dictionary_path = std::env::var("VIBRATO_DICTIONARY_PATH")
+            .map_err(|_| "VIBRATO_DICTIONARY_PATH must point to a vibrato system dictionary".to_string())?;
+        let dictionary_bytes =
+            std::fs::read(dictionary_path).map_err(|e| format!("failed to read vibrato dictionary: {}", e))?;
+        let dictionary = Dictionary::read(&dictionary_bytes[..])
+            .map_err(|e| format!("failed to parse vibrato dictionary: {}", e))?;
+
+        Ok(JapaneseTokenizer { dictionary })
+    }
+}
+
+impl Tokenizer for JapaneseTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        let mut tokenizer = VibratoTokenizer::new(&self.dictionary);
+        let mut worker = tokenizer.new_worker();
+        worker.reset_sentence(text);
+        worker.tokenize();
+
+        worker
+            .token_iter()
+            .map(|token| token.surface().to_string())
+            .collect()
+    }
+
+    fn reading(&self, word: &str) -> Option<String> {
+        let mut tokenizer = VibratoTokenizer::new(&self.dictionary);
+        let mut worker = tokenizer.new_worker();
+        worker.reset_sentence(word);
+        worker.tokenize();
+
+        // IPADIC-style dictionaries carry the katakana reading as the feature
+        // in position 7; fall back to no reading rather than guessing.
+        worker
+            .token_iter()
+            .next()
+            .and_then(|token| token.feature().split(',').nth(7))
+            .map(|reading| reading.to_string())
+    }
 }
 
 lazy_static! {
     static ref JIEBA: Jieba = Jieba::new();
+
+    // only languages that are always constructible (no external dictionary to
+    // load) go in this eager registry; `ja` is resolved lazily below so the
+    // vibrato dictionary is only touched when a `ja` request actually arrives
+    static ref TOKENIZERS: HashMap<&'static str, Box<dyn Tokenizer>> = {
+        let mut registry: HashMap<&'static str, Box<dyn Tokenizer>> = HashMap::new();
+        registry.insert("en", Box::new(EnglishTokenizer));
+        registry.insert("zh", Box::new(ChineseTokenizer));
+        registry
+    };
+}
+
+static JAPANESE_TOKENIZER: OnceLock<Result<JapaneseTokenizer, String>> = OnceLock::new();
+
+fn japanese_tokenizer() -> Result<&'static dyn Tokenizer, String> {
+    JAPANESE_TOKENIZER
+        .get_or_init(JapaneseTokenizer::try_new)
+        .as_ref()
+        .map(|tokenizer| tokenizer as &dyn Tokenizer)
+        .map_err(Clone::clone)
 }
 
-fn get_words_chinese<'a>(text: &'a str) -> Vec<&'a str> {
-    JIEBA.cut(text, false)
+fn resolve_tokenizer(lang: &str) -> Result<&'static dyn Tokenizer, String> {
+    if let Some(tokenizer) = TOKENIZERS.get(lang) {
+        return Ok(tokenizer.as_ref());
+    }
+
+    if lang == "ja" {
+        return japanese_tokenizer();
+    }
+
+    Err(format!("unsupported language for get_words: {}", lang))
 }
 
-pub fn get_unique_words(words: &Vec<&str>) -> serde_json::Value {
+pub fn get_words(text: &str, lang: &str) -> Result<Vec<String>, String> {
+    resolve_tokenizer(lang).map(|tokenizer| tokenizer.tokenize(text))
+}
+
+/// The reading/transliteration for `word` in `lang`, if that language's
+/// tokenizer offers one (pinyin for `zh`, furigana/romaji for `ja`).
+pub fn get_word_reading(word: &str, lang: &str) -> Option<String> {
+    resolve_tokenizer(lang).ok().and_then(|tokenizer| tokenizer.reading(word))
+}
+
+pub fn get_unique_words(words: &[String]) -> serde_json::Value {
     let mut unique_words = json!({});
 
     let map = match unique_words {
@@ -36,4 +165,4 @@ pub fn get_unique_words(words: &Vec<&str>) -> serde_json::Value {
     }
 
     unique_words
-}
\ No newline at end of file
+}
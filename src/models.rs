@@ -12,22 +12,22 @@ pub mod gen {
         use super::*;
 
         // General
-        #[derive(Serialize)]
+        #[derive(Serialize, utoipa::ToSchema)]
         pub struct StatusResponse {
             pub status: String,
         }
 
-        #[derive(Serialize)]
+        #[derive(Serialize, utoipa::ToSchema)]
         pub struct ResultResponse {
             pub success: bool,
         }
 
-        #[derive(Serialize)]
+        #[derive(Serialize, utoipa::ToSchema)]
         pub struct Message {
             pub message: &'static str,
         }
 
-        #[derive(Serialize)]
+        #[derive(Serialize, utoipa::ToSchema)]
         pub struct ErrorResponse {
             pub error: &'static str,
         }
@@ -49,7 +49,10 @@ pub mod user {
             pub created_on: SystemTime,
             pub study_lang: String,
             pub display_lang: String,
-            pub refresh_token: String,
+            // stored as text (`member` / `moderator` / `admin`) rather than an enum
+            // so the PostgresMapper derive doesn't need a custom FromSql; see
+            // `user::auth::Role` for the typed form used everywhere else.
+            pub role: String,
         }
 
         pub struct UpdateUserOpt {
@@ -57,7 +60,6 @@ pub mod user {
             pub pass: Option<String>,
             pub study_lang: Option<String>,
             pub display_lang: Option<String>,
-            pub refresh_token: Option<String>,
         }
 
         impl UpdateUserOpt {
@@ -67,7 +69,6 @@ pub mod user {
                     pass: None,
                     study_lang: None,
                     display_lang: None,
-                    refresh_token: None,
                 }
             }
 
@@ -77,12 +78,28 @@ pub mod user {
                     pass: req.password,
                     study_lang: req.study_lang,
                     display_lang: req.display_lang,
-                    refresh_token: None,
                 }
             }
         }
 
+        /// One logged-in device/session. Only `token_hash` is ever persisted —
+        /// the opaque refresh token handed to the client is hashed before it
+        /// reaches this table, the same way `pass` never stores a plaintext
+        /// password. `revoked` is set instead of deleting the row so a reused
+        /// revoked token can still be detected and reported.
         #[derive(Serialize, Deserialize, PostgresMapper)]
+        #[pg_mapper(table = "refresh_token")]
+        pub struct RefreshToken {
+            pub id: i32,
+            pub user_id: i32,
+            pub token_hash: String,
+            pub created_on: SystemTime,
+            pub expires_on: SystemTime,
+            pub device_label: Option<String>,
+            pub revoked: bool,
+        }
+
+        #[derive(Serialize, Deserialize, PostgresMapper, utoipa::ToSchema)]
         #[pg_mapper(table = "fruser")]
         pub struct SimpleUser {
             pub id: i32,
@@ -107,7 +124,7 @@ pub mod user {
         pub mod auth {
             use super::*;
 
-            #[derive(Deserialize)]
+            #[derive(Deserialize, utoipa::ToSchema)]
             pub struct RegisterRequest {
                 pub username: String,
                 pub password: String,
@@ -115,7 +132,7 @@ pub mod user {
                 pub display_lang: String,
             }
 
-            #[derive(Serialize)]
+            #[derive(Serialize, utoipa::ToSchema)]
             pub struct RegisterResponse {
                 pub user: SimpleUser,
             }
@@ -129,36 +146,88 @@ pub mod user {
                 }
             }
 
-            #[derive(Deserialize)]
+            #[derive(Deserialize, utoipa::ToSchema)]
             pub struct LoginRequest {
                 pub username: String,
                 pub password: String,
+                // shown back by the "list my sessions" endpoint so a user can
+                // tell which device a session belongs to before revoking it
+                pub device_label: Option<String>,
             }
 
-            #[derive(Serialize)]
+            // each login mints its own `RefreshToken` row, so logging in on a
+            // second device no longer invalidates the first
+            #[derive(Serialize, utoipa::ToSchema)]
             pub struct LoginResponse {
                 pub token: String,
                 pub refresh_token: String,
             }
 
-            #[derive(Deserialize)]
+            #[derive(Deserialize, utoipa::ToSchema)]
             pub struct RefreshRequest {
                 pub token: String,
                 pub refresh_token: String,
             }
 
-            #[derive(Serialize)]
+            // rotation: the presented `refresh_token` is revoked and replaced by
+            // a new one in the same response, so a stolen-but-already-used
+            // refresh token is detectable as reuse of a revoked token
+            #[derive(Serialize, utoipa::ToSchema)]
             pub struct RefreshResponse {
                 pub token: String,
+                pub refresh_token: String,
+            }
+
+            #[derive(Deserialize, utoipa::ToSchema)]
+            pub struct RevokeSessionRequest {
+                pub session_id: i32,
+            }
+
+            /// A session as shown back to its owner: never the token hash, just
+            /// enough to tell sessions apart before revoking one.
+            #[derive(Serialize, utoipa::ToSchema)]
+            pub struct SessionInfo {
+                pub id: i32,
+                #[schema(value_type = String, format = DateTime)]
+                pub created_on: SystemTime,
+                #[schema(value_type = String, format = DateTime)]
+                pub expires_on: SystemTime,
+                pub device_label: Option<String>,
+            }
+
+            impl SessionInfo {
+                #[inline]
+                pub fn new(token: &RefreshToken) -> SessionInfo {
+                    SessionInfo {
+                        id: token.id,
+                        created_on: token.created_on,
+                        expires_on: token.expires_on,
+                        device_label: token.device_label.clone(),
+                    }
+                }
+            }
+
+            #[derive(Serialize, utoipa::ToSchema)]
+            pub struct GetSessionsResponse {
+                pub sessions: Vec<SessionInfo>,
+            }
+
+            impl GetSessionsResponse {
+                #[inline]
+                pub fn new(sessions: Vec<SessionInfo>) -> GetSessionsResponse {
+                    GetSessionsResponse { sessions }
+                }
             }
         }
 
-        #[derive(Deserialize)]
+        // admin-only: served behind `RequirePermission<auth::ListUsers>` rather
+        // than a plain `ClaimsUser`, since the full user list is account data
+        #[derive(Deserialize, utoipa::IntoParams)]
         pub struct GetUsersRequest {
             pub offset: Option<i64>,
         }
 
-        #[derive(Serialize)]
+        #[derive(Serialize, utoipa::ToSchema)]
         pub struct GetUsersResponse {
             pub users: Vec<SimpleUser>,
             pub count: i64,
@@ -172,7 +241,7 @@ pub mod user {
             }
         }
 
-        #[derive(Deserialize)]
+        #[derive(Deserialize, utoipa::ToSchema)]
         pub struct UpdateUserRequest {
             pub username: Option<String>,
             pub password: Option<String>,
@@ -184,6 +253,83 @@ pub mod user {
     pub mod auth {
         use super::db::*;
         use super::*;
+        use std::marker::PhantomData;
+        use std::str::FromStr;
+
+        #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Role {
+            Member,
+            Moderator,
+            Admin,
+        }
+
+        impl Role {
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    Role::Member => "member",
+                    Role::Moderator => "moderator",
+                    Role::Admin => "admin",
+                }
+            }
+
+            pub fn has_permission(&self, permission: Permission) -> bool {
+                match permission {
+                    Permission::CreateSystemArticle => matches!(self, Role::Admin),
+                    Permission::ModerateArticles => matches!(self, Role::Admin | Role::Moderator),
+                    Permission::ListUsers => matches!(self, Role::Admin),
+                }
+            }
+        }
+
+        impl FromStr for Role {
+            type Err = String;
+
+            // `fruser.role` has a `CHECK (role IN ('member', 'moderator', 'admin'))`
+            // constraint, so any other value reaching this parse means the row is
+            // corrupt (or a role was added to the DB without updating this enum) —
+            // that's worth a hard error, not a silent downgrade to `Member`.
+            fn from_str(role: &str) -> Result<Role, String> {
+                match role {
+                    "member" => Ok(Role::Member),
+                    "moderator" => Ok(Role::Moderator),
+                    "admin" => Ok(Role::Admin),
+                    other => Err(format!("unrecognized role: {}", other)),
+                }
+            }
+        }
+
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        pub enum Permission {
+            // admins only: `is_system` articles are served to every user of a
+            // language, so minting one needs more trust than uploading your own
+            CreateSystemArticle,
+            // moderators and admins: editing/deleting uploads that aren't yours
+            ModerateArticles,
+            // admins only: the full user list is account-management data
+            ListUsers,
+        }
+
+        /// Marks a zero-sized type as standing for one `Permission`, so it can be
+        /// used as the `P` in `RequirePermission<P>` without an enum const generic.
+        pub trait PermissionMarker {
+            const PERMISSION: Permission;
+        }
+
+        pub struct CreateSystemArticle;
+        impl PermissionMarker for CreateSystemArticle {
+            const PERMISSION: Permission = Permission::CreateSystemArticle;
+        }
+
+        pub struct ModerateArticles;
+        impl PermissionMarker for ModerateArticles {
+            const PERMISSION: Permission = Permission::ModerateArticles;
+        }
+
+        pub struct ListUsers;
+        impl PermissionMarker for ListUsers {
+            const PERMISSION: Permission = Permission::ListUsers;
+        }
 
         #[derive(Serialize, Deserialize)]
         pub struct ClaimsUser {
@@ -192,6 +338,7 @@ pub mod user {
             pub created_on: SystemTime,
             pub study_lang: String,
             pub display_lang: String,
+            pub role: Role,
         }
 
         impl ClaimsUser {
@@ -203,6 +350,10 @@ pub mod user {
                     created_on: user.created_on,
                     study_lang: user.study_lang.clone(),
                     display_lang: user.display_lang.clone(),
+                    role: user
+                        .role
+                        .parse()
+                        .expect("fruser.role constraint should guarantee a valid role"),
                 }
             }
         }
@@ -224,6 +375,37 @@ pub mod user {
             }
         }
 
+        /// `FromRequest` extractor that only resolves for a `ClaimsUser` whose
+        /// `Role` has the `P` permission, e.g. `RequirePermission<ListUsers>`.
+        /// Rejects with `ErrorUnauthorized` the same way `ClaimsUser` itself does,
+        /// so handlers that swap one extractor for the other don't change their
+        /// error handling.
+        pub struct RequirePermission<P: PermissionMarker> {
+            pub user: ClaimsUser,
+            _permission: PhantomData<P>,
+        }
+
+        impl<P: PermissionMarker> FromRequest for RequirePermission<P> {
+            type Error = Error;
+            type Future = Ready<Result<Self, Self::Error>>;
+            type Config = ();
+
+            #[inline]
+            fn from_request(req: &HttpRequest, _: &mut dev::Payload) -> Self::Future {
+                match crate::auth::attempt_req_token_auth(req) {
+                    Ok(user) if user.role.has_permission(P::PERMISSION) => ok(RequirePermission {
+                        user,
+                        _permission: PhantomData,
+                    }),
+                    Ok(_) => err(ErrorUnauthorized("forbidden")),
+                    Err(error) => {
+                        eprintln!("{}", error);
+                        err(ErrorUnauthorized("auth_fail"))
+                    }
+                }
+            }
+        }
+
         #[derive(Serialize, Deserialize)]
         pub struct TokenClaims {
             pub exp: usize,
@@ -237,19 +419,36 @@ pub mod user {
         pub mod db {
             use super::*;
 
-            #[derive(Serialize, Deserialize, PostgresMapper)]
+            #[derive(Serialize, Deserialize, PostgresMapper, utoipa::ToSchema)]
             #[pg_mapper(table = "user_word_data")]
             pub struct UserWordData {
+                // keyed by word; each entry is a `WordSrsData` rather than a bare
+                // status string, so `word_status_data` keeps its column name but
+                // now doubles as the SRS store `crate::srs` schedules reviews from
                 pub word_status_data: serde_json::Value,
                 pub word_definition_data: serde_json::Value,
             }
+
+            /// The SM-2 scheduling state kept per word, per language, inside a
+            /// user's `word_status_data`. `status` keeps the existing
+            /// known/learning/unknown label; the rest is the SM-2 state `crate::srs`
+            /// reads and rewrites on each review.
+            #[derive(Serialize, Deserialize, Clone, utoipa::ToSchema)]
+            pub struct WordSrsData {
+                pub status: String,
+                pub repetitions: u32,
+                pub ease_factor: f32,
+                pub interval_days: u32,
+                #[schema(value_type = String, format = DateTime)]
+                pub due: SystemTime,
+            }
         }
 
         pub mod net {
             use super::db::*;
             use super::*;
 
-            #[derive(Serialize)]
+            #[derive(Serialize, utoipa::ToSchema)]
             pub struct GetWordDataResponse {
                 pub data: UserWordData,
             }
@@ -260,26 +459,71 @@ pub mod user {
                 }
             }
 
-            #[derive(Deserialize)]
+            #[derive(Deserialize, utoipa::ToSchema)]
             pub struct UpdateWordStatusRequest {
                 pub lang: String,
                 pub word: String,
                 pub status: String,
             }
 
-            #[derive(Deserialize)]
+            #[derive(Deserialize, utoipa::ToSchema)]
             pub struct BatchUpdateWordStatusRequest {
                 pub lang: String,
                 pub words: Vec<String>,
                 pub status: String,
             }
 
-            #[derive(Deserialize)]
+            #[derive(Deserialize, utoipa::ToSchema)]
             pub struct UpdateWordDefinitionRequest {
                 pub lang: String,
                 pub word: String,
                 pub definition: String,
             }
+
+            #[derive(Deserialize, utoipa::IntoParams)]
+            pub struct GetDueWordsRequest {
+                pub lang: String,
+                pub limit: Option<i64>,
+            }
+
+            #[derive(Serialize, utoipa::ToSchema)]
+            pub struct DueWord {
+                pub word: String,
+                #[schema(value_type = String, format = DateTime)]
+                pub due: SystemTime,
+            }
+
+            #[derive(Serialize, utoipa::ToSchema)]
+            pub struct GetDueWordsResponse {
+                pub words: Vec<DueWord>,
+            }
+
+            impl GetDueWordsResponse {
+                #[inline]
+                pub fn new(words: Vec<DueWord>) -> GetDueWordsResponse {
+                    GetDueWordsResponse { words }
+                }
+            }
+
+            #[derive(Deserialize, utoipa::ToSchema)]
+            pub struct ReviewWordRequest {
+                pub lang: String,
+                pub word: String,
+                // SM-2 recall quality, 0 (total blank) to 5 (perfect recall)
+                pub quality: u8,
+            }
+
+            #[derive(Serialize, utoipa::ToSchema)]
+            pub struct ReviewWordResponse {
+                pub data: WordSrsData,
+            }
+
+            impl ReviewWordResponse {
+                #[inline]
+                pub fn new(data: WordSrsData) -> ReviewWordResponse {
+                    ReviewWordResponse { data }
+                }
+            }
         }
     }
 }
@@ -290,7 +534,7 @@ pub mod article {
     pub mod db {
         use super::*;
 
-        #[derive(Serialize, Deserialize, PostgresMapper)]
+        #[derive(Serialize, Deserialize, PostgresMapper, utoipa::ToSchema)]
         #[pg_mapper(table = "article")]
         pub struct Article {
             pub id: i32,
@@ -302,6 +546,7 @@ pub mod article {
             pub sentences: serde_json::Value,
             pub unique_words: serde_json::Value,
             pub page_data: serde_json::Value,
+            #[schema(value_type = String, format = DateTime)]
             pub created_on: SystemTime,
             pub is_system: bool,
             pub uploader_id: i32,
@@ -309,7 +554,7 @@ pub mod article {
             pub tags: Vec<String>,
         }
 
-        #[derive(Serialize, Deserialize, PostgresMapper)]
+        #[derive(Serialize, Deserialize, PostgresMapper, utoipa::ToSchema)]
         #[pg_mapper(table = "article")]
         pub struct SimpleArticle {
             pub id: i32,
@@ -321,6 +566,7 @@ pub mod article {
             // no sentences
             // no unique words
             // no pages
+            #[schema(value_type = String, format = DateTime)]
             pub created_on: SystemTime,
             pub is_system: bool,
             // no uploader_id
@@ -334,35 +580,55 @@ pub mod article {
         use super::*;
 
         // get article list
-        #[derive(Deserialize)]
+        #[derive(Deserialize, utoipa::IntoParams)]
         pub struct GetArticlesRequest {
             pub limit: Option<i64>,
             pub offset: Option<i64>,
             pub lang: Option<String>,
             pub search: Option<String>,
+            // "comprehensibility" orders by `crate::comprehensibility`'s i+1
+            // ranking instead of the default (most recent first)
+            pub sort: Option<String>,
         }
 
-        #[derive(Serialize)]
+        /// How much of an article's vocabulary the requesting user already
+        /// knows, from `crate::comprehensibility::known_unknown_counts`. Carried
+        /// alongside `SimpleArticle` rather than folded into it so the plain
+        /// listing and PostgresMapper row type stay a direct column mapping.
+        #[derive(Serialize, utoipa::ToSchema)]
+        pub struct ArticleProgress {
+            pub known_words: i64,
+            pub unknown_words: i64,
+        }
+
+        #[derive(Serialize, utoipa::ToSchema)]
+        pub struct SimpleArticleWithProgress {
+            #[serde(flatten)]
+            pub article: SimpleArticle,
+            pub progress: ArticleProgress,
+        }
+
+        #[derive(Serialize, utoipa::ToSchema)]
         pub struct GetArticlesResponse {
-            pub articles: Vec<SimpleArticle>,
+            pub articles: Vec<SimpleArticleWithProgress>,
             pub count: i64,
         }
 
         impl GetArticlesResponse {
             #[inline]
-            pub fn new(articles: Vec<SimpleArticle>) -> GetArticlesResponse {
+            pub fn new(articles: Vec<SimpleArticleWithProgress>) -> GetArticlesResponse {
                 let count = articles.len() as i64;
                 GetArticlesResponse { articles, count }
             }
         }
 
         // get full article
-        #[derive(Deserialize)]
+        #[derive(Deserialize, utoipa::IntoParams)]
         pub struct ArticleRequest {
             pub article_id: i32,
         }
 
-        #[derive(Serialize)]
+        #[derive(Serialize, utoipa::ToSchema)]
         pub struct GetFullArticleResponse {
             pub article: Article,
         }
@@ -374,8 +640,27 @@ pub mod article {
             }
         }
 
+        // comprehensibility of a single article for the requesting user;
+        // reuses `ArticleRequest` since the input shape is identical
+        #[derive(Serialize, utoipa::ToSchema)]
+        pub struct ComprehensibilityResponse {
+            pub progress: ArticleProgress,
+            // known_words / (known_words + unknown_words), 0.0 if unique_words is empty
+            pub comprehensibility: f64,
+        }
+
+        impl ComprehensibilityResponse {
+            #[inline]
+            pub fn new(progress: ArticleProgress, comprehensibility: f64) -> ComprehensibilityResponse {
+                ComprehensibilityResponse {
+                    progress,
+                    comprehensibility,
+                }
+            }
+        }
+
         // post new article
-        #[derive(Deserialize)]
+        #[derive(Deserialize, utoipa::ToSchema)]
         pub struct NewArticleRequest {
             pub title: String,
             pub author: Option<String>,
@@ -383,9 +668,14 @@ pub mod article {
             pub language: String,
             pub tags: Option<Vec<String>>,
             pub is_private: bool,
+            // handlers must check `Role::has_permission(Permission::CreateSystemArticle)`
+            // before honoring `true` here; a non-admin request must fall back to
+            // a regular (non-system) upload rather than reject outright (the
+            // `RequirePermission` extractor would reject it)
+            pub is_system: bool,
         }
 
-        #[derive(Serialize)]
+        #[derive(Serialize, utoipa::ToSchema)]
         pub struct NewArticleResponse {
             pub article: Article,
         }
@@ -398,7 +688,7 @@ pub mod article {
         }
 
         // get user uploaded article list
-        #[derive(Deserialize)]
+        #[derive(Deserialize, utoipa::IntoParams)]
         pub struct GetUserArticlesRequest {
             pub limit: Option<i64>,
             pub offset: Option<i64>,
@@ -0,0 +1,61 @@
+use crate::models::{db, net};
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+/// The machine-readable contract for every `net` DTO in the crate. There are
+/// no `paths(...)` here: this snapshot doesn't include the `actix_web::App`
+/// wiring that maps handlers to routes, so the handler-level `#[utoipa::path]`
+/// annotations that would normally populate `paths` live alongside that
+/// wiring rather than here. Wherever the app is assembled, merge the output
+/// of `ApiDoc::openapi()` with those per-handler docs before serving it.
+///
+/// `GetUsersRequest`, `GetDueWordsRequest`, `GetArticlesRequest`,
+/// `ArticleRequest`, and `GetUserArticlesRequest` are deliberately absent:
+/// they're query-parameter structs (`utoipa::IntoParams`, not `ToSchema`) and
+/// belong in each handler's `#[utoipa::path(params(...))]`, not here.
+#[derive(OpenApi)]
+#[openapi(components(schemas(
+    net::StatusResponse,
+    net::ResultResponse,
+    net::Message,
+    net::ErrorResponse,
+    db::SimpleUser,
+    net::RegisterRequest,
+    net::RegisterResponse,
+    net::LoginRequest,
+    net::LoginResponse,
+    net::RefreshRequest,
+    net::RefreshResponse,
+    net::RevokeSessionRequest,
+    net::SessionInfo,
+    net::GetSessionsResponse,
+    net::GetUsersResponse,
+    net::UpdateUserRequest,
+    db::UserWordData,
+    db::WordSrsData,
+    net::GetWordDataResponse,
+    net::UpdateWordStatusRequest,
+    net::BatchUpdateWordStatusRequest,
+    net::UpdateWordDefinitionRequest,
+    net::DueWord,
+    net::GetDueWordsResponse,
+    net::ReviewWordRequest,
+    net::ReviewWordResponse,
+    db::Article,
+    db::SimpleArticle,
+    net::ArticleProgress,
+    net::SimpleArticleWithProgress,
+    net::GetArticlesResponse,
+    net::GetFullArticleResponse,
+    net::ComprehensibilityResponse,
+    net::NewArticleRequest,
+    net::NewArticleResponse,
+)))]
+pub struct ApiDoc;
+
+/// A Swagger UI service serving this document at `/api-docs/openapi.json`,
+/// browsable at `/swagger-ui/`. `App::service(openapi::swagger_ui())` wherever
+/// the app is built.
+pub fn swagger_ui() -> SwaggerUi {
+    SwaggerUi::new("/swagger-ui/{_:.*}").url("/api-docs/openapi.json", ApiDoc::openapi())
+}
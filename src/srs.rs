@@ -0,0 +1,111 @@
+use crate::models::db::WordSrsData;
+use std::time::{Duration, SystemTime};
+
+const INITIAL_EASE_FACTOR: f32 = 2.5;
+const MIN_EASE_FACTOR: f32 = 1.3;
+const SECONDS_PER_DAY: u64 = 60 * 60 * 24;
+
+/// Applies the SM-2 recurrence to `current` (or a fresh card, if this is the
+/// word's first review) for a review submitted with recall `quality` in
+/// `0..=5`, returning the updated schedule.
+pub fn review(current: Option<WordSrsData>, quality: u8, now: SystemTime) -> WordSrsData {
+    let quality = quality.min(5);
+    let mut data = current.unwrap_or(WordSrsData {
+        status: "learning".to_string(),
+        repetitions: 0,
+        ease_factor: INITIAL_EASE_FACTOR,
+        interval_days: 0,
+        due: now,
+    });
+
+    if quality >= 3 {
+        data.interval_days = match data.repetitions {
+            0 => 1,
+            1 => 6,
+            _ => (data.interval_days as f32 * data.ease_factor).round() as u32,
+        };
+        data.repetitions += 1;
+    } else {
+        data.repetitions = 0;
+        data.interval_days = 1;
+    }
+
+    let quality_deficit = 5.0 - quality as f32;
+    data.ease_factor = (data.ease_factor + 0.1 - quality_deficit * (0.08 + quality_deficit * 0.02))
+        .max(MIN_EASE_FACTOR);
+
+    data.due = now + Duration::from_secs(data.interval_days as u64 * SECONDS_PER_DAY);
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(repetitions: u32, ease_factor: f32, interval_days: u32) -> WordSrsData {
+        WordSrsData {
+            status: "learning".to_string(),
+            repetitions,
+            ease_factor,
+            interval_days,
+            due: SystemTime::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn first_successful_review_sets_interval_to_one_day() {
+        let result = review(None, 5, SystemTime::UNIX_EPOCH);
+
+        assert_eq!(result.repetitions, 1);
+        assert_eq!(result.interval_days, 1);
+    }
+
+    #[test]
+    fn second_successful_review_sets_interval_to_six_days() {
+        let result = review(Some(card(1, INITIAL_EASE_FACTOR, 1)), 4, SystemTime::UNIX_EPOCH);
+
+        assert_eq!(result.repetitions, 2);
+        assert_eq!(result.interval_days, 6);
+    }
+
+    #[test]
+    fn later_successful_reviews_multiply_interval_by_ease_factor() {
+        let result = review(Some(card(2, 2.0, 6)), 5, SystemTime::UNIX_EPOCH);
+
+        assert_eq!(result.repetitions, 3);
+        assert_eq!(result.interval_days, 12); // round(6 * 2.0)
+    }
+
+    #[test]
+    fn failing_a_review_resets_repetitions_and_interval() {
+        let result = review(Some(card(5, 2.2, 30)), 2, SystemTime::UNIX_EPOCH);
+
+        assert_eq!(result.repetitions, 0);
+        assert_eq!(result.interval_days, 1);
+    }
+
+    #[test]
+    fn ease_factor_increases_on_a_perfect_review() {
+        let result = review(Some(card(1, 2.5, 1)), 5, SystemTime::UNIX_EPOCH);
+
+        assert!((result.ease_factor - 2.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn ease_factor_is_clamped_to_the_1_3_floor() {
+        let result = review(Some(card(3, 1.3, 6)), 0, SystemTime::UNIX_EPOCH);
+
+        assert_eq!(result.ease_factor, MIN_EASE_FACTOR);
+    }
+
+    #[test]
+    fn due_date_advances_by_interval_days() {
+        let result = review(None, 5, SystemTime::UNIX_EPOCH);
+
+        assert_eq!(
+            result.due,
+            SystemTime::UNIX_EPOCH + Duration::from_secs(SECONDS_PER_DAY)
+        );
+    }
+}